@@ -3,6 +3,343 @@
 //! Defines the [`Polytope<V>`](struct.Polytope.html) data structure and related methods for
 //! constructing [polytopes](https://en.wikipedia.org/wiki/Polytope).
 
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+
+pub mod mesh;
+pub mod off;
+pub mod transform;
+pub mod vector;
+pub mod vertex;
+
+/// The absolute tolerance used by [`convex_hull()`](struct.Polytope.html#method.convex_hull) to
+/// decide whether a point lies on a hyperplane (rather than strictly beyond it) and whether a
+/// candidate direction is affinely independent from the current basis.
+const EPSILON: f64 = 1e-9;
+
+/// Error returned by [`Polytope::convex_hull()`](struct.Polytope.html#method.convex_hull) when no
+/// full-dimensional hull can be built from the given points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvexHullError {
+    /// No points were supplied.
+    NoPoints,
+    /// Fewer than `dimension + 1` affinely independent points were found among the input, so the
+    /// points do not span a full-dimensional hull.
+    Degenerate,
+}
+
+impl fmt::Display for ConvexHullError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConvexHullError::NoPoints => write!(f, "no points were supplied"),
+            ConvexHullError::Degenerate => {
+                write!(f, "points are affinely dependent and do not span a full-dimensional hull")
+            }
+        }
+    }
+}
+
+impl Error for ConvexHullError {}
+
+/// Error returned by [`Polytope::is_valid()`](struct.Polytope.html#method.is_valid) naming the
+/// first incidence that violates the abstract-polytope axioms, so polytopes built by hand or via
+/// import can be debugged.
+///
+/// Ranks follow the same convention as elsewhere: vertices are rank 0 and `elements[d]` is rank
+/// `d + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// An element's subelement list names the same lower-ranked element more than once.
+    DuplicateSubelement {
+        /// The rank of the offending element.
+        rank: usize,
+        /// The index of the offending element within its rank.
+        index: usize,
+    },
+    /// The diamond condition failed: for the element at `(rank, index)` and a sub-subelement at
+    /// `(rank - 2, sub_index)`, the number of rank `rank - 1` elements strictly between them was
+    /// `count` instead of the required 2.
+    DiamondViolation {
+        /// The rank of the higher element of the pair.
+        rank: usize,
+        /// The index of the higher element within its rank.
+        index: usize,
+        /// The index of the lower element within rank `rank - 2`.
+        sub_index: usize,
+        /// The number of intermediate elements actually found.
+        count: usize,
+    },
+    /// The diamond condition's degenerate case at the bottom of the lattice, between the (implicit)
+    /// null face and an edge (rank 1): an edge must have exactly 2 vertex subelements.
+    EdgeVertexCount {
+        /// The index of the offending edge.
+        index: usize,
+        /// The number of vertex subelements actually found.
+        count: usize,
+    },
+    /// The element at `(rank, index)` isn't listed as a subelement of anything at rank
+    /// `rank + 1`, so it's disconnected from the rest of the lattice above it.
+    UnreferencedElement {
+        /// The rank of the orphaned element.
+        rank: usize,
+        /// The index of the orphaned element within its rank.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ValidationError::DuplicateSubelement { rank, index } => {
+                write!(f, "element {} at rank {} lists a subelement more than once", index, rank)
+            }
+            ValidationError::DiamondViolation { rank, index, sub_index, count } => {
+                write!(f,
+                       "diamond condition violated between element {} at rank {} and element {} \
+                        at rank {}: expected 2 intermediate elements, found {}",
+                       index, rank, sub_index, rank - 2, count)
+            }
+            ValidationError::EdgeVertexCount { index, count } => {
+                write!(f, "edge {} has {} vertex subelements, expected exactly 2", index, count)
+            }
+            ValidationError::UnreferencedElement { rank, index } => {
+                write!(f,
+                       "element {} at rank {} isn't a subelement of anything at rank {}",
+                       index, rank, rank + 1)
+            }
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Error returned by [`Polytope::validate()`](struct.Polytope.html#method.validate), the full
+/// abstract-polytope checker: well-formed subelement indices, the diamond condition, and strong
+/// flag-connectivity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolytopeError {
+    /// An element's subelement list names an index that's out of range for its own rank.
+    InvalidSubelementIndex {
+        /// The rank of the offending element.
+        rank: usize,
+        /// The index of the offending element within its rank.
+        index: usize,
+        /// The out-of-range subelement index it lists.
+        sub_index: usize,
+    },
+    /// The diamond condition (or the duplicate-subelement check it builds on) failed; see
+    /// [`is_valid()`](struct.Polytope.html#method.is_valid).
+    Diamond(ValidationError),
+    /// The flag-adjacency graph — maximal flags joined when they differ in exactly one rank — is
+    /// disconnected. Names a vertex whose flags fall outside the component containing the first
+    /// flag.
+    Disconnected {
+        /// The rank of the stranded element; always 0 (a vertex).
+        rank: usize,
+        /// The index of the stranded vertex.
+        index: usize,
+    },
+}
+
+impl fmt::Display for PolytopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PolytopeError::InvalidSubelementIndex { rank, index, sub_index } => {
+                write!(f,
+                       "element {} at rank {} lists out-of-range subelement index {}",
+                       index, rank, sub_index)
+            }
+            PolytopeError::Diamond(ref cause) => write!(f, "{}", cause),
+            PolytopeError::Disconnected { rank, index } => {
+                write!(f,
+                       "element {} at rank {} is not flag-connected to the rest of the polytope",
+                       index, rank)
+            }
+        }
+    }
+}
+
+impl Error for PolytopeError {}
+
+/// A disjoint-set forest with union by size and path compression, used by
+/// [`Polytope::validate()`](struct.Polytope.html#method.validate) to test flag-connectivity.
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(count: usize) -> Self {
+        UnionFind { parent: (0..count).collect(), size: vec![1; count] }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return;
+        }
+        if self.size[ra] < self.size[rb] {
+            ::std::mem::swap(&mut ra, &mut rb);
+        }
+        self.parent[rb] = ra;
+        self.size[ra] += self.size[rb];
+    }
+}
+
+fn vector_sub(a: &[f64], b: &[f64]) -> Vec<f64> {
+    a.iter().zip(b.iter()).map(|(x, y)| x - y).collect()
+}
+
+fn vector_dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Computes the determinant of a square matrix given as a list of row vectors, via recursive
+/// cofactor expansion along the first row.
+fn determinant(matrix: &[Vec<f64>]) -> f64 {
+    if matrix.len() == 1 {
+        return matrix[0][0];
+    }
+    let mut sum = 0.0;
+    let mut sign = 1.0;
+    for col in 0..matrix.len() {
+        let minor: Vec<Vec<f64>> = matrix[1..].iter()
+            .map(|row| row.iter().enumerate()
+                .filter(|&(c, _)| c != col)
+                .map(|(_, &x)| x)
+                .collect())
+            .collect();
+        sum += sign * matrix[0][col] * determinant(&minor);
+        sign = -sign;
+    }
+    sum
+}
+
+/// Computes an outward-pointing (unnormalized) normal and offset for the hyperplane through
+/// `points` (`points.len()` affinely independent points spanning a space of that same
+/// dimension), oriented away from `interior`.
+///
+/// The normal is the generalized cross product of the `points.len() - 1` edge vectors from
+/// `points[0]`, obtained via cofactor expansion (the same construction as a 3-D cross product,
+/// generalized to `n` dimensions).
+fn facet_hyperplane(points: &[Vec<f64>], interior: &[f64]) -> (Vec<f64>, f64) {
+    let dimension = points[0].len();
+    let edges: Vec<Vec<f64>> = points[1..].iter().map(|p| vector_sub(p, &points[0])).collect();
+
+    let mut normal = vec![0.0; dimension];
+    for (i, n) in normal.iter_mut().enumerate() {
+        let minor: Vec<Vec<f64>> = edges.iter()
+            .map(|row| row.iter().enumerate()
+                .filter(|&(c, _)| c != i)
+                .map(|(_, &x)| x)
+                .collect())
+            .collect();
+        let cofactor = determinant(&minor);
+        *n = if i % 2 == 0 { cofactor } else { -cofactor };
+    }
+
+    let offset = vector_dot(&normal, &points[0]);
+    if vector_dot(&normal, interior) > offset {
+        (normal.iter().map(|x| -x).collect(), -offset)
+    } else {
+        (normal, offset)
+    }
+}
+
+/// Finds `dimension + 1` affinely independent points among `coords` (starting from index 0) via
+/// incremental Gram-Schmidt, to seed the initial simplex of [`convex_hull()`]
+/// (struct.Polytope.html#method.convex_hull).
+fn find_initial_simplex(coords: &[Vec<f64>], dimension: usize) -> Option<Vec<usize>> {
+    let mut simplex = vec![0];
+    let mut basis = Vec::<Vec<f64>>::new();
+    for i in 1..coords.len() {
+        if simplex.len() == dimension + 1 {
+            break;
+        }
+        let mut w = vector_sub(&coords[i], &coords[simplex[0]]);
+        for b in &basis {
+            let coeff = vector_dot(&w, b);
+            for (wk, bk) in w.iter_mut().zip(b.iter()) {
+                *wk -= coeff * bk;
+            }
+        }
+        let norm = vector_dot(&w, &w).sqrt();
+        if norm > EPSILON {
+            basis.push(w.iter().map(|x| x / norm).collect());
+            simplex.push(i);
+        }
+    }
+    if simplex.len() == dimension + 1 { Some(simplex) } else { None }
+}
+
+/// Lists the ridges (the subsets obtained by dropping exactly one vertex) of a simplex's sorted
+/// vertex set.
+fn ridges(vertices: &[usize]) -> Vec<Vec<usize>> {
+    (0..vertices.len())
+        .map(|skip| vertices.iter().enumerate()
+            .filter(|&(i, _)| i != skip)
+            .map(|(_, &v)| v)
+            .collect())
+        .collect()
+}
+
+/// A simplicial facet of an in-progress convex hull: a `dimension`-vertex simplex together with
+/// the outward-pointing hyperplane it spans.
+struct Facet {
+    vertices: Vec<usize>,
+    normal: Vec<f64>,
+    offset: f64,
+}
+
+/// Finds the root of `i`'s group in a union-find forest, path-compressing along the way.
+fn find_root(parents: &mut [usize], i: usize) -> usize {
+    if parents[i] != i {
+        parents[i] = find_root(parents, parents[i]);
+    }
+    parents[i]
+}
+
+/// Groups the indices of `facets` that share a common hyperplane (the same outward-pointing unit
+/// normal and offset, up to [`EPSILON`]) into the same slice, so the triangulated facets that
+/// [`convex_hull()`](struct.Polytope.html#method.convex_hull) produces for a flat input region
+/// merge back into a single polygonal face instead of leaking the triangulation's internal seams.
+fn group_coplanar_facets(facets: &[Facet]) -> Vec<Vec<usize>> {
+    let unit_planes: Vec<(Vec<f64>, f64)> = facets.iter().map(|f| {
+        let norm = vector_dot(&f.normal, &f.normal).sqrt();
+        (f.normal.iter().map(|x| x / norm).collect(), f.offset / norm)
+    }).collect();
+
+    let mut parents: Vec<usize> = (0..facets.len()).collect();
+    for i in 0..facets.len() {
+        for j in (i + 1)..facets.len() {
+            let (ni, oi) = &unit_planes[i];
+            let (nj, oj) = &unit_planes[j];
+            let coplanar = vector_dot(ni, nj) > 1.0 - EPSILON && (oi - oj).abs() < EPSILON;
+            if coplanar {
+                let (ri, rj) = (find_root(&mut parents, i), find_root(&mut parents, j));
+                if ri != rj {
+                    parents[ri] = rj;
+                }
+            }
+        }
+    }
+
+    let mut groups = HashMap::<usize, Vec<usize>>::new();
+    for i in 0..facets.len() {
+        let root = find_root(&mut parents, i);
+        groups.entry(root).or_default().push(i);
+    }
+    groups.into_values().collect()
+}
+
 macro_rules! boxed {
     [$($x:expr),*] => (Box::new([$($x),*]));
 }
@@ -113,6 +450,46 @@ impl<V> Polytope<V> {
         self.elements[dimension].as_ref()
     }
 
+    /// Builds a polytope directly from a vertex list and explicit element incidence lists, as
+    /// produced by format importers or other low-level constructors.
+    ///
+    /// The caller is responsible for ensuring the incidence structure is well-formed.
+    pub fn from_elements(vertices: Vec<V>, elements: Vec<Vec<Element>>) -> Self {
+        Self::from_vecs(vertices, elements)
+    }
+
+    /// Walks the boundary edges of the 2-face at `face_index` (an element of
+    /// [`elements(1)`](#method.elements)) into an ordered cycle of vertex indices.
+    ///
+    /// This requires the face's edges (`elements(0)`) to form a single closed loop, as they do
+    /// for any genuine polygon boundary.
+    pub fn face_cycle(&self, face_index: usize) -> Vec<usize> {
+        let edges = &self.elements[1][face_index];
+
+        let mut adjacency = HashMap::<usize, Vec<usize>>::new();
+        for &e in edges.iter() {
+            let pair = &self.elements[0][e];
+            let (a, b) = (pair[0], pair[1]);
+            adjacency.entry(a).or_default().push(b);
+            adjacency.entry(b).or_default().push(a);
+        }
+
+        let start = self.elements[0][edges[0]][0];
+        let mut cycle = vec![start];
+        let mut previous = None;
+        let mut current = start;
+        loop {
+            let next = adjacency[&current].iter().cloned().find(|&v| Some(v) != previous).unwrap();
+            if next == start {
+                break;
+            }
+            cycle.push(next);
+            previous = Some(current);
+            current = next;
+        }
+        cycle
+    }
+
     fn from_vecs(vertices: Vec<V>, elements: Vec<Vec<Element>>) -> Self {
         let mut elements = elements;
 
@@ -240,11 +617,546 @@ impl<V> Polytope<V> {
 
         Self::from_vecs(new_vertices, new_elements)
     }
+
+    /// Returns the number of elements at the given rank, treating vertices as rank 0 and
+    /// `elements[d]` as rank `d + 1`.
+    fn rank_size(&self, rank: usize) -> usize {
+        if rank == 0 {
+            self.vertices.len()
+        } else {
+            self.elements[rank - 1].len()
+        }
+    }
+
+    /// Lends the subelements of the element at the given rank and index, treating a vertex
+    /// (rank 0) as having no subelements.
+    fn rank_subelements(&self, rank: usize, index: usize) -> &[usize] {
+        if rank == 0 {
+            &[]
+        } else {
+            self.elements[rank - 1][index].as_ref()
+        }
+    }
+
+    /// Computes the [Cartesian product](https://en.wikipedia.org/wiki/Product_of_two_polytopes)
+    /// (duoprism) of `self` and `other`.
+    ///
+    /// Elements are indexed by pairs `(a, b)` where `a` is an element of `self` at rank `i`, `b`
+    /// is an element of `other` at rank `j`, and the pair lives at rank `i + j` of the result. A
+    /// pair of vertices (rank 0 on both sides) is combined into a new vertex via `combine`; every
+    /// other pair becomes a higher dimensional element whose subelements are the union of
+    /// "subelements of `a`, paired with `b`" and "`a`, paired with subelements of `b`".
+    ///
+    /// The resulting polytope has dimension `self.dimension() + other.dimension()`. Taking the
+    /// product with a point is the identity, and [`extrude()`](#method.extrude) is the special
+    /// case of taking the product with a line segment.
+    pub fn product<W, U, F>(&self, other: &Polytope<W>, combine: F) -> Polytope<U>
+        where F: Fn(&V, &W) -> U
+    {
+        let p = self.dimension();
+        let q = other.dimension();
+        let max_rank = p + q;
+
+        // For each target rank, the ordered list of (i, offset) blocks that make up its element
+        // list, where a block holds every pair (a, b) with a at rank i of self and b at rank
+        // (target - i) of other.
+        let mut blocks = Vec::<Vec<(usize, usize)>>::with_capacity(max_rank + 1);
+        for rank in 0..=max_rank {
+            let lo = rank.saturating_sub(q);
+            let hi = if rank < p { rank } else { p };
+            let mut offset = 0;
+            let mut rank_blocks = Vec::<(usize, usize)>::new();
+            for i in lo..=hi {
+                let j = rank - i;
+                rank_blocks.push((i, offset));
+                offset += self.rank_size(i) * other.rank_size(j);
+            }
+            blocks.push(rank_blocks);
+        }
+        let index_of = |rank: usize, i: usize, a: usize, j: usize, b: usize| -> usize {
+            let &(_, offset) = blocks[rank].iter().find(|&&(bi, _)| bi == i).unwrap();
+            offset + a * other.rank_size(j) + b
+        };
+
+        // Combine vertices (rank 0).
+        let mut new_vertices = Vec::<U>::new();
+        for a in self.vertices.iter() {
+            for b in other.vertices.iter() {
+                new_vertices.push(combine(a, b));
+            }
+        }
+
+        // Build the elements of every higher rank block by block.
+        let mut new_elements = Vec::<Vec<Element>>::new();
+        for rank in 1..=max_rank {
+            let lo = rank.saturating_sub(q);
+            let hi = if rank < p { rank } else { p };
+            let mut rank_elements = Vec::<Element>::new();
+            for i in lo..=hi {
+                let j = rank - i;
+                for a in 0..self.rank_size(i) {
+                    for b in 0..other.rank_size(j) {
+                        let mut subs = Vec::<usize>::new();
+                        for &a_sub in self.rank_subelements(i, a).iter() {
+                            subs.push(index_of(rank - 1, i - 1, a_sub, j, b));
+                        }
+                        for &b_sub in other.rank_subelements(j, b).iter() {
+                            subs.push(index_of(rank - 1, i, a, j - 1, b_sub));
+                        }
+                        rank_elements.push(collect!(subs.into_iter(), usize));
+                    }
+                }
+            }
+            new_elements.push(rank_elements);
+        }
+
+        Polytope::<U>::from_vecs(new_vertices, new_elements)
+    }
+
+    /// Collects the indices of every rank-0 vertex reachable from the element at the given rank
+    /// and index, descending through subelements.
+    fn collect_vertices(&self, rank: usize, index: usize, out: &mut BTreeSet<usize>) {
+        if rank == 0 {
+            out.insert(index);
+        } else {
+            for &sub in self.rank_subelements(rank, index) {
+                self.collect_vertices(rank - 1, sub, out);
+            }
+        }
+    }
+
+    /// Returns the combinatorial [dual](https://en.wikipedia.org/wiki/Dual_polyhedron) of the
+    /// polytope, reversing the face lattice so that a proper element of rank `k` becomes a proper
+    /// element of rank `n - 1 - k` (where `n` is [`dimension()`](#method.dimension)): vertices
+    /// become facets and facets become vertices. The body (the single rank-`n` element) has no
+    /// counterpart among the proper ranks, so the dual's own body is synthesized the same way
+    /// [`convex_hull()`](#method.convex_hull) synthesizes one: a single rank-`n` element covering
+    /// every rank-`(n - 1)` element of the dual.
+    ///
+    /// Since the stored `elements` only record downward incidence, the dual is built from the
+    /// upward (super-element) incidence instead: the dual subelements of a face are exactly its
+    /// original superelements.
+    ///
+    /// The `V` payload of each new vertex (an old facet) is produced by `facet_centroid`, which
+    /// receives the sorted, deduplicated indices of the original vertices incident to that facet.
+    ///
+    /// Applying `dual()` twice reproduces the original incidence structure. Panics if `self` has
+    /// dimension 0 (a facet-less point has no dual).
+    pub fn dual<F>(&self, facet_centroid: F) -> Self
+        where F: Fn(&[usize]) -> V
+    {
+        let n = self.dimension();
+        assert!(n >= 1, "dual() requires a polytope of dimension at least 1");
+
+        // Build upward incidence: supers[d][i] lists the indices of the dimension-d elements
+        // (rank d + 1) that contain the element i at dimension d - 1 (rank d).
+        let mut supers: Vec<Vec<Vec<usize>>> =
+            (0..n).map(|r| vec![Vec::<usize>::new(); self.rank_size(r)]).collect();
+        for (d, elems) in self.elements.iter().enumerate().take(n) {
+            for (i, e) in elems.iter().enumerate() {
+                for &sub in e.iter() {
+                    supers[d][sub].push(i);
+                }
+            }
+        }
+
+        // New vertices: one per old facet (rank n - 1).
+        let mut new_vertices = Vec::<V>::new();
+        for i in 0..self.rank_size(n - 1) {
+            let mut vertices = BTreeSet::new();
+            self.collect_vertices(n - 1, i, &mut vertices);
+            let vertices: Vec<usize> = vertices.into_iter().collect();
+            new_vertices.push(facet_centroid(&vertices));
+        }
+
+        // New elements at rank r (1..n) mirror the old elements at rank n - 1 - r, with
+        // subelements given by the original super-element incidence.
+        let mut new_elements = Vec::<Vec<Element>>::new();
+        for r in 1..n {
+            let old_rank = n - 1 - r;
+            let mut elems = Vec::<Element>::new();
+            for sub_supers in supers[old_rank].iter() {
+                elems.push(collect!(sub_supers.iter().cloned(), usize));
+            }
+            new_elements.push(elems);
+        }
+
+        // Synthesize the dual's own body: a single element covering every rank-(n - 1) element.
+        let top_size = new_elements.last().map_or(new_vertices.len(), |e| e.len());
+        new_elements.push(vec![collect!((0..top_size), usize)]);
+
+        Self::from_vecs(new_vertices, new_elements)
+    }
+
+    /// Builds the [convex hull](https://en.wikipedia.org/wiki/Convex_hull) of a cloud of points
+    /// using the incremental "beneath-beyond" algorithm (as used by qhull): starting from an
+    /// initial simplex, each remaining point that lies beyond some current facet is coned onto
+    /// the horizon of the facets it sees, after which those facets are replaced.
+    ///
+    /// `coords` extracts the coordinate slice of a point; every point must have the same number
+    /// of coordinates, which determines the dimension of the resulting polytope. Points that end
+    /// up strictly inside the hull, as well as duplicates and points that are merely coplanar with
+    /// an existing facet (rather than beyond it), are dropped rather than becoming vertices.
+    /// Triangulated facets that end up sharing a hyperplane (e.g. the faces of a cube, which the
+    /// algorithm initially triangulates) are merged into a single polygonal face rather than left
+    /// as separate simplices.
+    /// Returns [`ConvexHullError::Degenerate`](enum.ConvexHullError.html) if fewer than
+    /// `dimension + 1` of the points are affinely independent.
+    pub fn convex_hull<F>(points: Vec<V>, coords: F) -> Result<Self, ConvexHullError>
+        where F: Fn(&V) -> &[f64]
+    {
+        if points.is_empty() {
+            return Err(ConvexHullError::NoPoints);
+        }
+        let dimension = coords(&points[0]).len();
+        if dimension == 0 {
+            return Err(ConvexHullError::Degenerate);
+        }
+        let point_coords: Vec<Vec<f64>> = points.iter().map(|p| coords(p).to_vec()).collect();
+
+        // facet_hyperplane()'s cofactor construction degenerates at dimension 1 (a facet there
+        // has 0 edge vectors, so its cofactor expansion can't tell which side is outward); the
+        // hull of a set of scalars is just its min and max, so handle it directly instead.
+        if dimension == 1 {
+            let scalars: Vec<f64> = point_coords.iter().map(|c| c[0]).collect();
+            let min_index = (0..scalars.len())
+                .min_by(|&a, &b| scalars[a].partial_cmp(&scalars[b]).unwrap())
+                .unwrap();
+            let max_index = (0..scalars.len())
+                .max_by(|&a, &b| scalars[a].partial_cmp(&scalars[b]).unwrap())
+                .unwrap();
+            if scalars[max_index] - scalars[min_index] < EPSILON {
+                return Err(ConvexHullError::Degenerate);
+            }
+
+            let mut points: Vec<Option<V>> = points.into_iter().map(Some).collect();
+            let vertices = vec![points[min_index].take().unwrap(), points[max_index].take().unwrap()];
+            let edges: Vec<Element> = vec![boxed![0, 1]];
+            return Ok(Self::from_vecs(vertices, vec![edges]));
+        }
+
+        let simplex = find_initial_simplex(&point_coords, dimension)
+            .ok_or(ConvexHullError::Degenerate)?;
+
+        // Seed one facet per vertex of the initial simplex, omitting that vertex; the simplex
+        // vertex itself is guaranteed to lie on the interior side.
+        let mut facets: Vec<Facet> = simplex.iter().map(|&omit| {
+            let vertices: Vec<usize> = simplex.iter().cloned().filter(|&v| v != omit).collect();
+            let facet_points: Vec<Vec<f64>> = vertices.iter().map(|&v| point_coords[v].clone()).collect();
+            let (normal, offset) = facet_hyperplane(&facet_points, &point_coords[omit]);
+            Facet { vertices, normal, offset }
+        }).collect();
+
+        let mut used: HashSet<usize> = simplex.iter().cloned().collect();
+
+        for (p, p_coords) in point_coords.iter().enumerate() {
+            if used.contains(&p) {
+                continue;
+            }
+
+            let visible: HashSet<usize> = facets.iter().enumerate()
+                .filter(|&(_, f)| vector_dot(&f.normal, p_coords) > f.offset + EPSILON)
+                .map(|(i, _)| i)
+                .collect();
+            if visible.is_empty() {
+                continue; // the point is inside the current hull
+            }
+
+            // A ridge shared by two visible facets is interior to the visible region; a ridge
+            // bordering exactly one visible facet is on the horizon and gets coned to the point.
+            let mut ridge_facets = HashMap::<Vec<usize>, Vec<usize>>::new();
+            for (i, f) in facets.iter().enumerate() {
+                for ridge in ridges(&f.vertices) {
+                    ridge_facets.entry(ridge).or_default().push(i);
+                }
+            }
+            let mut horizon = Vec::<Vec<usize>>::new();
+            for &i in &visible {
+                for ridge in ridges(&facets[i].vertices) {
+                    let shared_with_visible = ridge_facets[&ridge].iter()
+                        .any(|&j| j != i && visible.contains(&j));
+                    if !shared_with_visible {
+                        horizon.push(ridge);
+                    }
+                }
+            }
+
+            // Any point already on the hull is interior to the convex hull of the hull's own
+            // vertices, so their centroid is a safe interior reference for orientation.
+            let hull_vertices: HashSet<usize> =
+                facets.iter().flat_map(|f| f.vertices.iter().cloned()).collect();
+            let mut interior = vec![0.0; dimension];
+            for &v in &hull_vertices {
+                for (ik, vk) in interior.iter_mut().zip(point_coords[v].iter()) {
+                    *ik += vk;
+                }
+            }
+            for ik in interior.iter_mut() {
+                *ik /= hull_vertices.len() as f64;
+            }
+
+            let mut new_facets = Vec::<Facet>::new();
+            for ridge in &horizon {
+                let mut vertices = ridge.clone();
+                vertices.push(p);
+                vertices.sort();
+                let facet_points: Vec<Vec<f64>> =
+                    vertices.iter().map(|&v| point_coords[v].clone()).collect();
+                let (normal, offset) = facet_hyperplane(&facet_points, &interior);
+                new_facets.push(Facet { vertices, normal, offset });
+            }
+
+            facets = facets.into_iter().enumerate()
+                .filter(|&(i, _)| !visible.contains(&i))
+                .map(|(_, f)| f)
+                .chain(new_facets)
+                .collect();
+            used.insert(p);
+        }
+
+        // Merge triangulated facets that share a hyperplane into a single polygonal face (e.g. the
+        // two triangles beneath-beyond leaves on each side of a cube collapse into one square),
+        // then decompose each face's own lattice of subsimplices, from ridges down to vertices,
+        // deduplicating shared faces by vertex set.
+        let facet_groups = if dimension >= 2 {
+            group_coplanar_facets(&facets)
+        } else {
+            (0..facets.len()).map(|i| vec![i]).collect()
+        };
+        let facet_count = facet_groups.len();
+        let mut elements = vec![Vec::<Element>::new(); dimension];
+        elements[dimension - 1] = vec![collect!((0..facet_count), usize)];
+
+        let mut rank_tuples: Vec<Vec<usize>> = if dimension >= 2 {
+            // A ridge shared by exactly one simplex in a merged group is on the face's boundary;
+            // one shared by two is an internal seam between two triangulated pieces of the same
+            // face, and is dropped.
+            let mut ridge_index = HashMap::<Vec<usize>, usize>::new();
+            let mut ridge_tuples = Vec::<Vec<usize>>::new();
+            let mut facet_elements = Vec::<Element>::new();
+            for members in &facet_groups {
+                let mut ridge_counts = HashMap::<Vec<usize>, usize>::new();
+                for &m in members {
+                    for ridge in ridges(&facets[m].vertices) {
+                        *ridge_counts.entry(ridge).or_insert(0) += 1;
+                    }
+                }
+                let boundary: Vec<usize> = ridge_counts.into_iter()
+                    .filter(|&(_, count)| count == 1)
+                    .map(|(ridge, _)| *ridge_index.entry(ridge.clone()).or_insert_with(|| {
+                        ridge_tuples.push(ridge);
+                        ridge_tuples.len() - 1
+                    }))
+                    .collect();
+                facet_elements.push(collect!(boundary.into_iter(), usize));
+            }
+            elements[dimension - 2] = facet_elements;
+            ridge_tuples
+        } else {
+            facets.into_iter().map(|f| f.vertices).collect()
+        };
+
+        let top_rank = if dimension >= 2 { dimension - 1 } else { dimension };
+        for rank in (1..top_rank).rev() {
+            let mut sub_index = HashMap::<Vec<usize>, usize>::new();
+            let mut sub_tuples = Vec::<Vec<usize>>::new();
+            let mut rank_elements = Vec::<Element>::new();
+            for tuple in &rank_tuples {
+                let subs: Vec<usize> = (0..tuple.len()).map(|skip| {
+                    let sub: Vec<usize> = tuple.iter().enumerate()
+                        .filter(|&(i, _)| i != skip)
+                        .map(|(_, &v)| v)
+                        .collect();
+                    *sub_index.entry(sub.clone()).or_insert_with(|| {
+                        sub_tuples.push(sub);
+                        sub_tuples.len() - 1
+                    })
+                }).collect();
+                rank_elements.push(collect!(subs.into_iter(), usize));
+            }
+            elements[rank - 1] = rank_elements;
+            rank_tuples = sub_tuples;
+        }
+
+        // After the decomposition, `rank_tuples` holds the surviving vertices as singletons of
+        // global point indices, in their final, compact order.
+        let mut points: Vec<Option<V>> = points.into_iter().map(Some).collect();
+        let vertices: Vec<V> = rank_tuples.iter()
+            .map(|t| points[t[0]].take().unwrap())
+            .collect();
+
+        Ok(Self::from_vecs(vertices, elements))
+    }
+
+    /// Checks that the stored incidence structure is a valid abstract polytope, via the
+    /// [diamond condition](https://en.wikipedia.org/wiki/Abstract_polytope#Definition): for
+    /// every pair of incident elements `F < H` whose ranks differ by exactly 2, there must be
+    /// exactly two elements `G` with `F < G < H`.
+    ///
+    /// Also rejects an element whose subelement list names the same lower-ranked element more
+    /// than once. Useful for checking polytopes built by hand (via
+    /// [`from_elements()`](#method.from_elements)) or produced by an importer.
+    ///
+    /// This does not check rank-connectivity: a polytope assembled from two disjoint, otherwise
+    /// valid pieces (e.g. two squares stored as one polytope) passes `is_valid()`. Use
+    /// [`validate()`](#method.validate) when that needs to be ruled out too.
+    pub fn is_valid(&self) -> Result<(), ValidationError> {
+        let n = self.dimension();
+
+        for rank in 1..=n {
+            for (index, e) in self.elements[rank - 1].iter().enumerate() {
+                let mut seen = HashSet::new();
+                for &s in e.iter() {
+                    if !seen.insert(s) {
+                        return Err(ValidationError::DuplicateSubelement { rank, index });
+                    }
+                }
+            }
+        }
+
+        // The diamond condition's bottom case, between the null face and an edge (rank 1): every
+        // edge must have exactly 2 vertices. The rank >= 2 loop below can't express this, since it
+        // derives F from the sub-subelements actually reachable through G, and there's no rank -1
+        // to enumerate F over here.
+        if n >= 1 {
+            for (index, e) in self.elements[0].iter().enumerate() {
+                if e.len() != 2 {
+                    return Err(ValidationError::EdgeVertexCount { index, count: e.len() });
+                }
+            }
+        }
+
+        for rank in 2..=n {
+            for h in 0..self.rank_size(rank) {
+                let gs = self.rank_subelements(rank, h);
+
+                let mut sub_subelements = BTreeSet::new();
+                for &g in gs {
+                    sub_subelements.extend(self.rank_subelements(rank - 1, g).iter().cloned());
+                }
+
+                for &f in &sub_subelements {
+                    let count = gs.iter()
+                        .filter(|&&g| self.rank_subelements(rank - 1, g).contains(&f))
+                        .count();
+                    if count != 2 {
+                        return Err(ValidationError::DiamondViolation {
+                            rank,
+                            index: h,
+                            sub_index: f,
+                            count,
+                        });
+                    }
+                }
+            }
+        }
+
+        // An element that's never listed as a subelement of anything at the rank above is simply
+        // invisible to the loops above, which only ever walk downward from the elements that were
+        // reached from the top: a stray extra edge or cell dropped into `elements` without being
+        // referenced from above would otherwise pass unnoticed.
+        for rank in 0..n {
+            let mut referenced = vec![false; self.rank_size(rank)];
+            for h in 0..self.rank_size(rank + 1) {
+                for &g in self.rank_subelements(rank + 1, h) {
+                    referenced[g] = true;
+                }
+            }
+            if let Some(index) = referenced.iter().position(|&r| !r) {
+                return Err(ValidationError::UnreferencedElement { rank, index });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the stored incidence structure satisfies the full set of [abstract-polytope
+    /// axioms](https://en.wikipedia.org/wiki/Abstract_polytope#Definition):
+    ///
+    /// 1. every subelement index lies within range for its own rank;
+    /// 2. the diamond condition, via [`is_valid()`](#method.is_valid);
+    /// 3. strong flag-connectivity — the graph of maximal flags (one element per rank, each
+    ///    incident to the next, joined when they differ in exactly one rank) is connected.
+    ///
+    /// Where [`is_valid()`](#method.is_valid) only checks incidence locally, between elements at
+    /// most two ranks apart, this additionally catches a polytope assembled from otherwise-valid pieces that
+    /// don't actually hang together, such as two disjoint cells stored as one polytope.
+    pub fn validate(&self) -> Result<(), PolytopeError> {
+        let n = self.dimension();
+
+        for rank in 1..=n {
+            for (index, e) in self.elements[rank - 1].iter().enumerate() {
+                for &sub_index in e.iter() {
+                    if sub_index >= self.rank_size(rank - 1) {
+                        return Err(PolytopeError::InvalidSubelementIndex { rank, index, sub_index });
+                    }
+                }
+            }
+        }
+
+        self.is_valid().map_err(PolytopeError::Diamond)?;
+
+        if n == 0 {
+            return Ok(());
+        }
+
+        // Enumerate every maximal flag (one element per rank, 0..=n, each a subelement of the
+        // next) as a Vec of per-rank indices, memoizing the fragments ending at each element so
+        // shared subfaces aren't re-expanded from scratch.
+        let mut memo = HashMap::<(usize, usize), Vec<Vec<usize>>>::new();
+        for rank in 0..=n {
+            for index in 0..self.rank_size(rank) {
+                let fragments = if rank == 0 {
+                    vec![vec![index]]
+                } else {
+                    let mut fragments = Vec::new();
+                    for &sub in self.rank_subelements(rank, index) {
+                        for prefix in &memo[&(rank - 1, sub)] {
+                            let mut fragment = prefix.clone();
+                            fragment.push(index);
+                            fragments.push(fragment);
+                        }
+                    }
+                    fragments
+                };
+                memo.insert((rank, index), fragments);
+            }
+        }
+        let flags: Vec<Vec<usize>> = (0..self.rank_size(n)).flat_map(|index| memo[&(n, index)].clone()).collect();
+
+        // Union flags that differ in exactly one rank: group by the per-rank indices with
+        // position `rank` removed, and union every flag within a group.
+        let mut forest = UnionFind::new(flags.len());
+        for rank in 0..=n {
+            let mut groups = HashMap::<Vec<usize>, Vec<usize>>::new();
+            for (i, flag) in flags.iter().enumerate() {
+                let key: Vec<usize> = flag.iter().enumerate()
+                    .filter(|&(r, _)| r != rank)
+                    .map(|(_, &f)| f)
+                    .collect();
+                groups.entry(key).or_default().push(i);
+            }
+            for group in groups.values() {
+                for i in 1..group.len() {
+                    forest.union(group[0], group[i]);
+                }
+            }
+        }
+
+        let root = forest.find(0);
+        for (i, flag) in flags.iter().enumerate() {
+            if forest.find(i) != root {
+                return Err(PolytopeError::Disconnected { rank: 0, index: flag[0] });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use ::Polytope;
+    use ::{ConvexHullError, Element, Polytope};
 
     #[derive(Debug)]
     struct MyVertex {
@@ -390,4 +1302,368 @@ mod tests {
             Box::new([7, 9, 5, 2, 3]),
         ]));
     }
+
+    #[test]
+    fn product_with_point_is_identity() {
+        let p = Polytope::<MyVertex>::new(Default::default());
+        let p = p.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let point = Polytope::<MyVertex>::new(Default::default());
+        let q = p.product(&point, |a, _| MyVertex { coords: a.coords.clone() });
+        assert_eq!(q.vertices.len(), p.vertices.len());
+        assert_eq!(q.elements.len(), p.elements.len());
+        for d in 0..p.elements.len() {
+            assert!(q.elements[d] == p.elements[d]);
+        }
+    }
+
+    #[test]
+    fn product_of_two_segments_is_rectangle() {
+        let point = Polytope::<MyVertex>::new(Default::default());
+        let segment = point.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let rectangle = segment.product(&segment, |a, b| {
+            MyVertex { coords: collect!(a.coords.iter().chain(b.coords.iter()).copied(), f64) }
+        });
+        assert_eq!(rectangle.dimension(), 2);
+        assert_eq!(rectangle.vertices().len(), 4);
+        assert_eq!(rectangle.elements(0).len(), 4);
+        assert_eq!(rectangle.elements(1).len(), 1);
+        assert!(rectangle.vertices[0].coords == Box::new([-1.0, -1.0]));
+        assert!(rectangle.vertices[1].coords == Box::new([-1.0, 1.0]));
+        assert!(rectangle.vertices[2].coords == Box::new([1.0, -1.0]));
+        assert!(rectangle.vertices[3].coords == Box::new([1.0, 1.0]));
+    }
+
+    #[test]
+    fn product_with_a_segment_matches_extrude_shape() {
+        // extrude() is the special case of product() with a 1-D polytope (a segment): both
+        // should produce the same element counts per rank, even though product()'s generic
+        // block layout orders the elements differently.
+        let point = Polytope::<MyVertex>::new(Default::default());
+        let triangle_ish = point.extrude(|v| v.promote(-1.0), |v| v.promote(1.0))
+            .extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let segment = Polytope::<MyVertex>::new(Default::default())
+            .extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+
+        let extruded = triangle_ish.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let producted = triangle_ish.product(&segment, |a, b| {
+            MyVertex { coords: collect!(a.coords.iter().chain(b.coords.iter()).copied(), f64) }
+        });
+
+        assert_eq!(producted.dimension(), extruded.dimension());
+        assert_eq!(producted.vertices().len(), extruded.vertices().len());
+        for rank in 0..extruded.dimension() {
+            assert_eq!(producted.elements(rank).len(), extruded.elements(rank).len());
+        }
+        assert!(producted.is_valid().is_ok());
+    }
+
+    #[test]
+    fn dual_of_line_is_isomorphic_to_the_line() {
+        let p = Polytope::<MyVertex>::new(Default::default());
+        let line = p.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let dual = line.dual(|_| MyVertex { coords: boxed![0.0] });
+        assert_eq!(dual.dimension(), line.dimension());
+        assert_eq!(dual.vertices().len(), line.vertices().len());
+        assert_eq!(dual.elements(0).len(), line.elements(0).len());
+        assert!(dual.elements[0] == Box::new([Box::new([0, 1])]));
+    }
+
+    #[test]
+    fn dual_of_cube_is_octahedron() {
+        let p = Polytope::<MyVertex>::new(Default::default());
+        let line = p.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let square = line.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let cube = square.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let dual = cube.dual(|_| MyVertex { coords: boxed![0.0, 0.0, 0.0] });
+        assert_eq!(dual.dimension(), 3);
+        assert_eq!(dual.vertices().len(), 6);
+        assert_eq!(dual.elements(0).len(), 12);
+        assert_eq!(dual.elements(1).len(), 8);
+        assert_eq!(dual.elements(2).len(), 1);
+        assert!(dual.is_valid().is_ok());
+    }
+
+    #[test]
+    fn dual_twice_reproduces_incidence() {
+        let p = Polytope::<MyVertex>::new(Default::default());
+        let p = p.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let rectangle = p.extrude(|v| v.promote(-2.0), |v| v.promote(2.0));
+        let dual = rectangle.dual(|_| MyVertex { coords: boxed![0.0, 0.0] });
+        // An absolute check on the single dual, not just the round trip: a rectangle's dual is
+        // itself a quadrilateral (4 vertices, 4 edges), so a dual that collapsed to a single
+        // point (e.g. from sourcing new vertices off the body instead of the facets) would still
+        // pass a "dual twice" comparison against the original rectangle without ever producing
+        // the wrong intermediate shape.
+        assert_eq!(dual.vertices().len(), 4);
+        assert_eq!(dual.elements(0).len(), 4);
+        let bidual = dual.dual(|_| MyVertex { coords: boxed![0.0, 0.0] });
+        assert_eq!(bidual.dimension(), rectangle.dimension());
+        assert_eq!(bidual.vertices().len(), rectangle.vertices().len());
+        for d in 0..rectangle.elements.len() {
+            assert_eq!(bidual.elements[d].len(), rectangle.elements[d].len());
+        }
+    }
+
+    #[derive(Debug)]
+    struct Point {
+        coords: Vec<f64>,
+    }
+
+    #[test]
+    fn convex_hull_of_square_drops_interior_point() {
+        let points = vec![
+            Point { coords: vec![0.0, 0.0] },
+            Point { coords: vec![1.0, 0.0] },
+            Point { coords: vec![1.0, 1.0] },
+            Point { coords: vec![0.0, 1.0] },
+            Point { coords: vec![0.5, 0.5] },
+        ];
+        let hull = Polytope::convex_hull(points, |p: &Point| p.coords.as_slice()).unwrap();
+        assert_eq!(hull.dimension(), 2);
+        assert_eq!(hull.vertices().len(), 4);
+        assert_eq!(hull.elements(0).len(), 4);
+        assert_eq!(hull.elements(1).len(), 1);
+        assert!(hull.vertices().iter().all(|v| v.coords != [0.5, 0.5]));
+    }
+
+    #[test]
+    fn convex_hull_of_cube_corners() {
+        let mut points = Vec::new();
+        for &x in &[0.0, 1.0] {
+            for &y in &[0.0, 1.0] {
+                for &z in &[0.0, 1.0] {
+                    points.push(Point { coords: vec![x, y, z] });
+                }
+            }
+        }
+        let hull = Polytope::convex_hull(points, |p: &Point| p.coords.as_slice()).unwrap();
+        assert_eq!(hull.dimension(), 3);
+        assert_eq!(hull.vertices().len(), 8);
+        // The beneath-beyond triangulation starts with 12 triangular facets, but the two
+        // triangles on each side of the cube are coplanar and must merge into one square face.
+        assert_eq!(hull.elements(0).len(), 12);
+        assert_eq!(hull.elements(1).len(), 6);
+        assert_eq!(hull.elements(2).len(), 1);
+    }
+
+    #[test]
+    fn convex_hull_of_cube_corners_dualizes_to_an_octahedron() {
+        // A cube's dual is an octahedron (6 vertices, one per face); if convex_hull() left the
+        // triangulation's 12 triangular facets unmerged, dual() would instead produce one vertex
+        // per triangle.
+        let mut points = Vec::new();
+        for &x in &[0.0, 1.0] {
+            for &y in &[0.0, 1.0] {
+                for &z in &[0.0, 1.0] {
+                    points.push(Point { coords: vec![x, y, z] });
+                }
+            }
+        }
+        let hull = Polytope::convex_hull(points, |p: &Point| p.coords.as_slice()).unwrap();
+        let dual = hull.dual(|_| Point { coords: vec![0.0, 0.0, 0.0] });
+        assert_eq!(dual.vertices().len(), 6);
+        assert_eq!(dual.elements(0).len(), 12);
+    }
+
+    #[test]
+    fn convex_hull_drops_a_point_coplanar_with_a_facet() {
+        // The centroid of the square base lies exactly on that facet's plane rather than beyond
+        // it, so it must be dropped just like a strictly interior point.
+        let points = vec![
+            Point { coords: vec![0.0, 0.0, 0.0] },
+            Point { coords: vec![1.0, 0.0, 0.0] },
+            Point { coords: vec![1.0, 1.0, 0.0] },
+            Point { coords: vec![0.0, 1.0, 0.0] },
+            Point { coords: vec![0.5, 0.5, 1.0] },
+            Point { coords: vec![0.5, 0.5, 0.0] },
+        ];
+        let hull = Polytope::convex_hull(points, |p: &Point| p.coords.as_slice()).unwrap();
+        assert_eq!(hull.vertices().len(), 5);
+        assert!(hull.vertices().iter().all(|v| v.coords != [0.5, 0.5, 0.0]));
+    }
+
+    #[test]
+    fn convex_hull_ignores_duplicate_points() {
+        let points = vec![
+            Point { coords: vec![0.0, 0.0] },
+            Point { coords: vec![1.0, 0.0] },
+            Point { coords: vec![1.0, 0.0] },
+            Point { coords: vec![1.0, 1.0] },
+            Point { coords: vec![0.0, 1.0] },
+        ];
+        let hull = Polytope::convex_hull(points, |p: &Point| p.coords.as_slice()).unwrap();
+        assert_eq!(hull.vertices().len(), 4);
+    }
+
+    #[test]
+    fn convex_hull_rejects_degenerate_input() {
+        let points = vec![
+            Point { coords: vec![0.0, 0.0, 0.0] },
+            Point { coords: vec![1.0, 0.0, 0.0] },
+            Point { coords: vec![2.0, 0.0, 0.0] },
+        ];
+        let result = Polytope::convex_hull(points, |p: &Point| p.coords.as_slice());
+        assert_eq!(result.unwrap_err(), ConvexHullError::Degenerate);
+    }
+
+    #[test]
+    fn convex_hull_of_1d_points_keeps_only_the_extremes() {
+        let points = vec![
+            Point { coords: vec![5.0] },
+            Point { coords: vec![1.0] },
+            Point { coords: vec![3.0] },
+            Point { coords: vec![-1.0] },
+            Point { coords: vec![10.0] },
+        ];
+        let hull = Polytope::convex_hull(points, |p: &Point| p.coords.as_slice()).unwrap();
+        assert_eq!(hull.vertices().len(), 2);
+        let mut values: Vec<f64> = hull.vertices().iter().map(|v| v.coords[0]).collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(values, vec![-1.0, 10.0]);
+        assert_eq!(hull.elements(0).len(), 1);
+    }
+
+    #[test]
+    fn convex_hull_rejects_degenerate_1d_input() {
+        let points = vec![
+            Point { coords: vec![2.0] },
+            Point { coords: vec![2.0] },
+            Point { coords: vec![2.0] },
+        ];
+        let result = Polytope::convex_hull(points, |p: &Point| p.coords.as_slice());
+        assert_eq!(result.unwrap_err(), ConvexHullError::Degenerate);
+    }
+
+    #[test]
+    fn prism_is_valid() {
+        let p = Polytope::<MyVertex>::new(Default::default());
+        let p = p.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let rectangle = p.extrude(|v| v.promote(-2.0), |v| v.promote(2.0));
+        let prism = rectangle.extrude(|v| v.promote(-3.0), |v| v.promote(3.0));
+        assert_eq!(prism.is_valid(), Ok(()));
+    }
+
+    #[test]
+    fn convex_hull_of_cube_is_valid() {
+        let mut points = Vec::new();
+        for &x in &[0.0, 1.0] {
+            for &y in &[0.0, 1.0] {
+                for &z in &[0.0, 1.0] {
+                    points.push(Point { coords: vec![x, y, z] });
+                }
+            }
+        }
+        let hull = Polytope::convex_hull(points, |p: &Point| p.coords.as_slice()).unwrap();
+        assert_eq!(hull.is_valid(), Ok(()));
+    }
+
+    #[test]
+    fn hand_built_polytope_with_a_missing_edge_fails_diamond_condition() {
+        // A square whose cell leaves out one of its four edges: the vertices of the missing edge
+        // each lie on only one of the cell's edges, so the diamond condition fails between the
+        // cell and those vertices.
+        let vertices = vec![
+            MyVertex { coords: boxed![0.0, 0.0] },
+            MyVertex { coords: boxed![1.0, 0.0] },
+            MyVertex { coords: boxed![1.0, 1.0] },
+            MyVertex { coords: boxed![0.0, 1.0] },
+        ];
+        let edges: Vec<Element> = vec![
+            boxed![0, 1],
+            boxed![1, 2],
+            boxed![2, 3],
+            boxed![3, 0],
+        ];
+        // The cell only references three of the four edges, so vertex 3 is only covered once.
+        let cells: Vec<Element> = vec![boxed![0, 1, 2]];
+        let polytope = Polytope::from_elements(vertices, vec![edges, cells]);
+        assert!(polytope.is_valid().is_err());
+    }
+
+    #[test]
+    fn validate_accepts_a_prism() {
+        let p = Polytope::<MyVertex>::new(Default::default());
+        let p = p.extrude(|v| v.promote(-1.0), |v| v.promote(1.0));
+        let rectangle = p.extrude(|v| v.promote(-2.0), |v| v.promote(2.0));
+        let prism = rectangle.extrude(|v| v.promote(-3.0), |v| v.promote(3.0));
+        assert_eq!(prism.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_an_out_of_range_subelement_index() {
+        let vertices = vec![
+            MyVertex { coords: boxed![0.0] },
+            MyVertex { coords: boxed![1.0] },
+        ];
+        let edges: Vec<Element> = vec![boxed![0, 2]];
+        let polytope = Polytope::from_elements(vertices, vec![edges]);
+        assert_eq!(polytope.validate(),
+                   Err(::PolytopeError::InvalidSubelementIndex { rank: 1, index: 0, sub_index: 2 }));
+    }
+
+    #[test]
+    fn validate_rejects_a_diamond_violation() {
+        let vertices = vec![
+            MyVertex { coords: boxed![0.0, 0.0] },
+            MyVertex { coords: boxed![1.0, 0.0] },
+            MyVertex { coords: boxed![1.0, 1.0] },
+            MyVertex { coords: boxed![0.0, 1.0] },
+        ];
+        let edges: Vec<Element> = vec![
+            boxed![0, 1],
+            boxed![1, 2],
+            boxed![2, 3],
+            boxed![3, 0],
+        ];
+        let cells: Vec<Element> = vec![boxed![0, 1, 2]];
+        let polytope = Polytope::from_elements(vertices, vec![edges, cells]);
+        assert!(polytope.validate().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_an_edge_not_referenced_by_any_cell() {
+        // The extra 5th edge is well-formed on its own but isn't a subelement of the cell, so
+        // it's invisible to a lattice walk that only descends from the top; validate() must
+        // still catch it via is_valid()'s unreferenced-element check rather than missing it.
+        let vertices = vec![
+            MyVertex { coords: boxed![0.0, 0.0] },
+            MyVertex { coords: boxed![1.0, 0.0] },
+            MyVertex { coords: boxed![1.0, 1.0] },
+            MyVertex { coords: boxed![0.0, 1.0] },
+        ];
+        let edges: Vec<Element> = vec![
+            boxed![0, 1],
+            boxed![1, 2],
+            boxed![2, 3],
+            boxed![3, 0],
+            boxed![0, 2],
+        ];
+        let cells: Vec<Element> = vec![boxed![0, 1, 2, 3]];
+        let polytope = Polytope::from_elements(vertices, vec![edges, cells]);
+        assert!(polytope.validate().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_two_disjoint_squares_stored_as_one_polytope() {
+        // Combinatorially valid on its own, each square passes is_valid(), but the two cells
+        // share no vertices or edges, so the flag graph has two components.
+        let vertices = vec![
+            MyVertex { coords: boxed![0.0, 0.0] },
+            MyVertex { coords: boxed![1.0, 0.0] },
+            MyVertex { coords: boxed![1.0, 1.0] },
+            MyVertex { coords: boxed![0.0, 1.0] },
+            MyVertex { coords: boxed![2.0, 0.0] },
+            MyVertex { coords: boxed![3.0, 0.0] },
+            MyVertex { coords: boxed![3.0, 1.0] },
+            MyVertex { coords: boxed![2.0, 1.0] },
+        ];
+        let edges: Vec<Element> = vec![
+            boxed![0, 1], boxed![1, 2], boxed![2, 3], boxed![3, 0],
+            boxed![4, 5], boxed![5, 6], boxed![6, 7], boxed![7, 4],
+        ];
+        let cells: Vec<Element> = vec![boxed![0, 1, 2, 3], boxed![4, 5, 6, 7]];
+        let polytope = Polytope::from_elements(vertices, vec![edges, cells]);
+        assert!(polytope.is_valid().is_ok());
+        assert!(matches!(polytope.validate(), Err(::PolytopeError::Disconnected { rank: 0, .. })));
+    }
 }
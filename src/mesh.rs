@@ -0,0 +1,212 @@
+//! Triangulates the 2-faces of a 3-D polytope into a renderable/printable triangle mesh, and
+//! writes the result as Wavefront [OBJ](https://en.wikipedia.org/wiki/Wavefront_.obj_file), the
+//! format used by common rendering and slicer tooling.
+
+use std::io::{self, Write};
+
+use EPSILON;
+use Polytope;
+
+/// A flat vertex-and-triangle-index buffer, as produced by [`triangulate()`](fn.triangulate.html).
+pub struct TriangleMesh {
+    vertices: Vec<[f64; 3]>,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl TriangleMesh {
+    /// Lends the vertex buffer.
+    pub fn vertices(&self) -> &[[f64; 3]] {
+        &self.vertices
+    }
+
+    /// Lends the triangle index buffer.
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+}
+
+/// Triangulates every 2-face of `polytope` into a [`TriangleMesh`](struct.TriangleMesh.html).
+///
+/// `coords` extracts the 3-D coordinate slice of a vertex (fewer than 3 coordinates are
+/// zero-padded). Each face's boundary is first walked into an ordered vertex cycle via
+/// [`face_cycle()`](../struct.Polytope.html#method.face_cycle), then ear-clipped, so non-convex
+/// faces triangulate correctly rather than just fanning from a single corner.
+pub fn triangulate<V, F>(polytope: &Polytope<V>, coords: F) -> TriangleMesh
+    where F: Fn(&V) -> &[f64]
+{
+    let vertices: Vec<[f64; 3]> = polytope.vertices().iter()
+        .map(|v| {
+            let c = coords(v);
+            [c.first().cloned().unwrap_or(0.0),
+             c.get(1).cloned().unwrap_or(0.0),
+             c.get(2).cloned().unwrap_or(0.0)]
+        })
+        .collect();
+
+    let mut triangles = Vec::new();
+    for face_index in 0..polytope.elements(1).len() {
+        let cycle = polytope.face_cycle(face_index);
+        triangles.extend(ear_clip(&cycle, &vertices));
+    }
+
+    TriangleMesh { vertices, triangles }
+}
+
+/// Writes `mesh` to `out` as Wavefront OBJ (`v x y z` vertex lines, 1-indexed `f a b c` face
+/// lines).
+pub fn write_obj<W: Write>(mesh: &TriangleMesh, out: &mut W) -> io::Result<()> {
+    for v in &mesh.vertices {
+        writeln!(out, "v {} {} {}", v[0], v[1], v[2])?;
+    }
+    for t in &mesh.triangles {
+        writeln!(out, "f {} {} {}", t[0] + 1, t[1] + 1, t[2] + 1)?;
+    }
+    Ok(())
+}
+
+/// Ear-clips an ordered, planar vertex cycle into triangles, falling back to a fan for any
+/// residual loop an ear can't be found in (e.g. a self-intersecting boundary).
+fn ear_clip(cycle: &[usize], vertices: &[[f64; 3]]) -> Vec<[usize; 3]> {
+    if cycle.len() < 3 {
+        return Vec::new();
+    }
+    if cycle.len() == 3 {
+        return vec![[cycle[0], cycle[1], cycle[2]]];
+    }
+
+    let normal = newell_normal(cycle, vertices);
+    let points: Vec<(f64, f64)> = cycle.iter().map(|&v| project2d(normal, vertices[v])).collect();
+
+    let mut remaining: Vec<usize> = (0..cycle.len()).collect();
+    let mut triangles = Vec::new();
+
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let ear = (0..n).find(|&i| {
+            let prev = remaining[(i + n - 1) % n];
+            let curr = remaining[i];
+            let next = remaining[(i + 1) % n];
+            let (a, b, c) = (points[prev], points[curr], points[next]);
+
+            signed_area2(a, b, c) > EPSILON &&
+                remaining.iter().cloned()
+                    .filter(|&j| j != prev && j != curr && j != next)
+                    .all(|j| !point_in_triangle(points[j], a, b, c))
+        });
+
+        match ear {
+            Some(i) => {
+                let prev = remaining[(i + n - 1) % n];
+                let curr = remaining[i];
+                let next = remaining[(i + 1) % n];
+                triangles.push([cycle[prev], cycle[curr], cycle[next]]);
+                remaining.remove(i);
+            }
+            None => break,
+        }
+    }
+
+    for i in 1..remaining.len() - 1 {
+        triangles.push([cycle[remaining[0]], cycle[remaining[i]], cycle[remaining[i + 1]]]);
+    }
+
+    triangles
+}
+
+/// The (unnormalized) normal of a planar vertex cycle via [Newell's
+/// method](https://www.researchgate.net/publication/202924969), which tolerates mildly non-planar
+/// or non-convex input.
+fn newell_normal(cycle: &[usize], vertices: &[[f64; 3]]) -> [f64; 3] {
+    let mut normal = [0.0; 3];
+    let n = cycle.len();
+    for i in 0..n {
+        let a = vertices[cycle[i]];
+        let b = vertices[cycle[(i + 1) % n]];
+        normal[0] += (a[1] - b[1]) * (a[2] + b[2]);
+        normal[1] += (a[2] - b[2]) * (a[0] + b[0]);
+        normal[2] += (a[0] - b[0]) * (a[1] + b[1]);
+    }
+    normal
+}
+
+/// Projects a 3-D point onto the 2-D plane best aligned with `normal`, by dropping the axis
+/// `normal` points most strongly along and flipping the remaining pair when needed to preserve
+/// the cycle's winding.
+fn project2d(normal: [f64; 3], p: [f64; 3]) -> (f64, f64) {
+    let abs = [normal[0].abs(), normal[1].abs(), normal[2].abs()];
+    if abs[0] >= abs[1] && abs[0] >= abs[2] {
+        if normal[0] >= 0.0 { (p[1], p[2]) } else { (p[2], p[1]) }
+    } else if abs[1] >= abs[2] {
+        if normal[1] >= 0.0 { (p[2], p[0]) } else { (p[0], p[2]) }
+    } else {
+        if normal[2] >= 0.0 { (p[0], p[1]) } else { (p[1], p[0]) }
+    }
+}
+
+/// Twice the signed area of triangle `a`, `b`, `c`; positive for counter-clockwise winding.
+fn signed_area2(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// Whether `p` lies inside or on the boundary of triangle `a`, `b`, `c` (of either winding).
+fn point_in_triangle(p: (f64, f64), a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> bool {
+    let d1 = signed_area2(p, a, b);
+    let d2 = signed_area2(p, b, c);
+    let d3 = signed_area2(p, c, a);
+
+    let has_neg = d1 < -EPSILON || d2 < -EPSILON || d3 < -EPSILON;
+    let has_pos = d1 > EPSILON || d2 > EPSILON || d3 > EPSILON;
+
+    !(has_neg && has_pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use Polytope;
+
+    #[derive(Debug)]
+    struct Point {
+        coords: Vec<f64>,
+    }
+
+    fn cube() -> Polytope<Point> {
+        let point = Polytope::new(Point { coords: vec![] });
+        let line = point.extrude(|_| Point { coords: vec![-1.0] }, |_| Point { coords: vec![1.0] });
+        let square = line.extrude(|v| Point { coords: vec![v.coords[0], -1.0] },
+                                   |v| Point { coords: vec![v.coords[0], 1.0] });
+        square.extrude(|v| Point { coords: vec![v.coords[0], v.coords[1], -1.0] },
+                        |v| Point { coords: vec![v.coords[0], v.coords[1], 1.0] })
+    }
+
+    #[test]
+    fn cube_triangulates_into_two_triangles_per_face() {
+        let mesh = super::triangulate(&cube(), |p| &p.coords);
+        assert_eq!(mesh.vertices().len(), 8);
+        assert_eq!(mesh.triangles().len(), 6 * 2);
+    }
+
+    #[test]
+    fn ear_clip_handles_a_non_convex_pentagon() {
+        // An arrow-shaped pentagon with a reflex vertex at index 4.
+        let vertices = vec![
+            [0.0, 0.0, 0.0],
+            [4.0, 0.0, 0.0],
+            [4.0, 4.0, 0.0],
+            [0.0, 4.0, 0.0],
+            [2.0, 2.0, 0.0],
+        ];
+        let cycle = [0, 1, 2, 3, 4];
+        let triangles = super::ear_clip(&cycle, &vertices);
+        assert_eq!(triangles.len(), 3);
+    }
+
+    #[test]
+    fn write_obj_emits_vertex_and_face_lines() {
+        let mesh = super::triangulate(&cube(), |p| &p.coords);
+        let mut buffer = Vec::<u8>::new();
+        super::write_obj(&mesh, &mut buffer).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        assert_eq!(text.lines().filter(|l| l.starts_with("v ")).count(), 8);
+        assert_eq!(text.lines().filter(|l| l.starts_with("f ")).count(), 12);
+    }
+}
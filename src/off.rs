@@ -0,0 +1,196 @@
+//! Serializes and parses polytopes using the Object File Format
+//! ([OFF](https://en.wikipedia.org/wiki/OFF_(file_format))), the common interchange format for
+//! polyhedral geometry used by tools such as miratope and qhull.
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+
+use {Element, Polytope};
+
+/// Writes `polytope` to `out` in the `nOFF` variant of the Object File Format.
+///
+/// `polytope` must have dimension at least 2 (vertices, edges and 2-faces); `coords` extracts
+/// the coordinate slice of a vertex. Returns an [`io::Error`](https://doc.rust-lang.org/std/io/struct.Error.html)
+/// of kind `InvalidData` if `polytope` doesn't meet that precondition.
+pub fn write<V, W, F>(polytope: &Polytope<V>, out: &mut W, coords: F) -> io::Result<()>
+    where W: Write,
+          F: Fn(&V) -> &[f64]
+{
+    if polytope.dimension() < 2 {
+        return Err(invalid("polytope must have dimension at least 2 to write as OFF"));
+    }
+
+    let dimension = polytope.vertices().first().map_or(0, |v| coords(v).len());
+    let faces = polytope.elements(1);
+
+    writeln!(out, "nOFF")?;
+    writeln!(out, "{}", dimension)?;
+    writeln!(out, "{} {} {}", polytope.vertices().len(), faces.len(), polytope.elements(0).len())?;
+
+    for vertex in polytope.vertices() {
+        let values: Vec<String> = coords(vertex).iter().map(|x| x.to_string()).collect();
+        writeln!(out, "{}", values.join(" "))?;
+    }
+
+    for i in 0..faces.len() {
+        let cycle = polytope.face_cycle(i);
+        let indices: Vec<String> = cycle.iter().map(|i| i.to_string()).collect();
+        writeln!(out, "{} {}", cycle.len(), indices.join(" "))?;
+    }
+
+    Ok(())
+}
+
+/// Parses a polytope in the `OFF`/`nOFF` variants of the Object File Format from `input`.
+///
+/// Only the vertex and 2-face lists are present in the file; edges are deduced from consecutive
+/// vertex pairs of each face and deduplicated across shared edges. `make_vertex` builds a `V`
+/// from a parsed coordinate slice.
+pub fn read<V, R, F>(input: &mut R, make_vertex: F) -> io::Result<Polytope<V>>
+    where R: BufRead,
+          F: Fn(&[f64]) -> V
+{
+    let mut lines = input.lines()
+        .map_while(Result::ok)
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'));
+
+    let header = lines.next().ok_or_else(|| invalid("missing OFF header"))?;
+    let is_n_off = header.starts_with('n');
+
+    let dimension = if is_n_off {
+        lines.next().ok_or_else(|| invalid("missing dimension line"))?
+            .parse::<usize>().map_err(|_| invalid("invalid dimension line"))?
+    } else {
+        3
+    };
+
+    let counts_line = lines.next().ok_or_else(|| invalid("missing counts line"))?;
+    let mut counts = counts_line.split_whitespace();
+    let vertex_count = parse_usize(counts.next())?;
+    let face_count = parse_usize(counts.next())?;
+
+    let mut vertices = Vec::with_capacity(vertex_count);
+    for _ in 0..vertex_count {
+        let line = lines.next().ok_or_else(|| invalid("missing vertex line"))?;
+        let coords: Vec<f64> = line.split_whitespace()
+            .take(dimension)
+            .map(|x| x.parse::<f64>().map_err(|_| invalid("invalid coordinate")))
+            .collect::<Result<_, _>>()?;
+        vertices.push(make_vertex(&coords));
+    }
+
+    let mut edge_index = BTreeMap::<(usize, usize), usize>::new();
+    let mut edges = Vec::<Element>::new();
+    let mut faces = Vec::<Element>::new();
+    for _ in 0..face_count {
+        let line = lines.next().ok_or_else(|| invalid("missing face line"))?;
+        let mut fields = line.split_whitespace();
+        let count = parse_usize(fields.next())?;
+        let face_vertices: Vec<usize> = fields.take(count)
+            .map(|x| x.parse::<usize>().map_err(|_| invalid("invalid vertex index")))
+            .collect::<Result<_, _>>()?;
+
+        let mut face_edges = Vec::<usize>::new();
+        for i in 0..face_vertices.len() {
+            let a = face_vertices[i];
+            let b = face_vertices[(i + 1) % face_vertices.len()];
+            let key = if a < b { (a, b) } else { (b, a) };
+            let next_index = edges.len();
+            let edge_index = *edge_index.entry(key).or_insert_with(|| {
+                edges.push(Box::new([key.0, key.1]));
+                next_index
+            });
+            face_edges.push(edge_index);
+        }
+        faces.push(face_edges.into_boxed_slice());
+    }
+
+    // A single face is itself the top (improper) element; more than one face means the faces
+    // are ridges of a genuine solid, so wrap them in a cell listing all of them, matching the
+    // invariant `convex_hull()` maintains that the top rank has exactly one element.
+    if faces.len() > 1 {
+        let cell: Element = (0..faces.len()).collect::<Vec<usize>>().into_boxed_slice();
+        Ok(Polytope::from_elements(vertices, vec![edges, faces, vec![cell]]))
+    } else {
+        Ok(Polytope::from_elements(vertices, vec![edges, faces]))
+    }
+}
+
+fn parse_usize(field: Option<&str>) -> io::Result<usize> {
+    field.ok_or_else(|| invalid("missing integer field"))?
+        .parse::<usize>().map_err(|_| invalid("invalid integer field"))
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use Polytope;
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        coords: Vec<f64>,
+    }
+
+    #[test]
+    fn write_then_read_round_trips_a_square() {
+        let p = Polytope::<Point>::new(Point { coords: vec![] });
+        let line = p.extrude(|_| Point { coords: vec![-1.0] }, |_| Point { coords: vec![1.0] });
+        let square = line.extrude(|v| Point { coords: vec![v.coords[0], -1.0] },
+                                   |v| Point { coords: vec![v.coords[0], 1.0] });
+
+        let mut buffer = Vec::<u8>::new();
+        super::write(&square, &mut buffer, |v| &v.coords).unwrap();
+
+        let parsed = super::read(&mut &buffer[..], |coords| {
+            Point { coords: coords.to_vec() }
+        }).unwrap();
+
+        assert_eq!(parsed.vertices().len(), square.vertices().len());
+        assert_eq!(parsed.elements(0).len(), square.elements(0).len());
+        assert_eq!(parsed.elements(1).len(), square.elements(1).len());
+        for (v, w) in parsed.vertices().iter().zip(square.vertices().iter()) {
+            assert_eq!(v.coords, w.coords);
+        }
+    }
+
+    #[test]
+    fn write_then_read_round_trips_a_cube() {
+        let p = Polytope::<Point>::new(Point { coords: vec![] });
+        let line = p.extrude(|_| Point { coords: vec![-1.0] }, |_| Point { coords: vec![1.0] });
+        let square = line.extrude(|v| Point { coords: vec![v.coords[0], -1.0] },
+                                   |v| Point { coords: vec![v.coords[0], 1.0] });
+        let cube = square.extrude(|v| Point { coords: vec![v.coords[0], v.coords[1], -1.0] },
+                                   |v| Point { coords: vec![v.coords[0], v.coords[1], 1.0] });
+
+        let mut buffer = Vec::<u8>::new();
+        super::write(&cube, &mut buffer, |v| &v.coords).unwrap();
+
+        let parsed = super::read(&mut &buffer[..], |coords| {
+            Point { coords: coords.to_vec() }
+        }).unwrap();
+
+        // The 6 parsed faces must be wrapped in a single top cell, or the round trip would
+        // silently drop the cube down to a dimension-2 polytope whose top rank is the faces.
+        assert_eq!(parsed.dimension(), cube.dimension());
+        assert_eq!(parsed.vertices().len(), cube.vertices().len());
+        assert_eq!(parsed.elements(0).len(), cube.elements(0).len());
+        assert_eq!(parsed.elements(1).len(), cube.elements(1).len());
+        assert_eq!(parsed.elements(2).len(), cube.elements(2).len());
+    }
+
+    #[test]
+    fn write_rejects_a_polytope_below_dimension_two() {
+        let p = Polytope::<Point>::new(Point { coords: vec![] });
+        let line = p.extrude(|_| Point { coords: vec![-1.0] }, |_| Point { coords: vec![1.0] });
+
+        let mut buffer = Vec::<u8>::new();
+        let err = super::write(&line, &mut buffer, |v| &v.coords).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,111 @@
+//! Affine transforms (translation, scaling and Givens-style rotation) over `f64` vertex
+//! coordinates, for composing rigid and scaling motions before applying them with
+//! [`Polytope::transform()`](../struct.Polytope.html#method.transform).
+
+/// An affine map `x -> M x + t` over `f64` coordinate vectors, built by composing translations,
+/// scalings and axis-plane rotations.
+#[derive(Debug, Clone)]
+pub struct AffineTransform {
+    matrix: Vec<Vec<f64>>,
+    translation: Vec<f64>,
+}
+
+impl AffineTransform {
+    /// The identity transform in `dimension` dimensions.
+    pub fn identity(dimension: usize) -> Self {
+        let mut matrix = vec![vec![0.0; dimension]; dimension];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+        AffineTransform { matrix, translation: vec![0.0; dimension] }
+    }
+
+    /// A pure translation by `offset`.
+    pub fn translation(offset: &[f64]) -> Self {
+        let mut transform = Self::identity(offset.len());
+        transform.translation = offset.to_vec();
+        transform
+    }
+
+    /// A non-uniform scaling by `factors`, one per axis.
+    pub fn scaling(factors: &[f64]) -> Self {
+        let mut transform = Self::identity(factors.len());
+        for (i, &factor) in factors.iter().enumerate() {
+            transform.matrix[i][i] = factor;
+        }
+        transform
+    }
+
+    /// A uniform scaling by `factor` in `dimension` dimensions.
+    pub fn uniform_scaling(factor: f64, dimension: usize) -> Self {
+        Self::scaling(&vec![factor; dimension])
+    }
+
+    /// A [Givens rotation](https://en.wikipedia.org/wiki/Givens_rotation) by `angle` radians in
+    /// the plane spanned by axes `i` and `j`.
+    pub fn rotation(dimension: usize, i: usize, j: usize, angle: f64) -> Self {
+        let mut transform = Self::identity(dimension);
+        let (sin, cos) = angle.sin_cos();
+        transform.matrix[i][i] = cos;
+        transform.matrix[j][j] = cos;
+        transform.matrix[i][j] = -sin;
+        transform.matrix[j][i] = sin;
+        transform
+    }
+
+    /// Composes `self` after `other`, i.e. the transform `x -> self.apply(other.apply(x))`.
+    pub fn compose(&self, other: &AffineTransform) -> Self {
+        let dimension = self.matrix.len();
+        let matrix: Vec<Vec<f64>> = self.matrix.iter()
+            .map(|row| {
+                (0..dimension)
+                    .map(|c| row.iter().zip(other.matrix.iter()).map(|(&m, other_row)| m * other_row[c]).sum())
+                    .collect()
+            })
+            .collect();
+        let translation = self.apply(&other.translation);
+        AffineTransform { matrix, translation }
+    }
+
+    /// Applies the transform to a point.
+    pub fn apply(&self, point: &[f64]) -> Vec<f64> {
+        self.matrix.iter().zip(self.translation.iter())
+            .map(|(row, &t)| {
+                row.iter().zip(point.iter()).map(|(m, p)| m * p).sum::<f64>() + t
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AffineTransform;
+
+    #[test]
+    fn translation_moves_a_point() {
+        let t = AffineTransform::translation(&[1.0, -2.0]);
+        assert_eq!(t.apply(&[0.0, 0.0]), vec![1.0, -2.0]);
+    }
+
+    #[test]
+    fn uniform_scaling_scales_every_axis() {
+        let t = AffineTransform::uniform_scaling(2.0, 3);
+        assert_eq!(t.apply(&[1.0, 2.0, 3.0]), vec![2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn rotation_by_half_pi_swaps_axes() {
+        let t = AffineTransform::rotation(2, 0, 1, ::std::f64::consts::FRAC_PI_2);
+        let result = t.apply(&[1.0, 0.0]);
+        assert!((result[0] - 0.0).abs() < 1e-9);
+        assert!((result[1] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn compose_applies_other_first() {
+        let translate = AffineTransform::translation(&[1.0, 0.0]);
+        let scale = AffineTransform::uniform_scaling(2.0, 2);
+        let composed = translate.compose(&scale);
+        assert_eq!(composed.apply(&[1.0, 1.0]), vec![3.0, 2.0]);
+    }
+}
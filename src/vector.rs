@@ -0,0 +1,5 @@
+//! A thin alias for the coordinate storage used by
+//! [`CoordVertex<F>`](../vertex/struct.CoordVertex.html).
+
+/// A vector of `F`-typed coordinates.
+pub type Vector<F> = Vec<F>;
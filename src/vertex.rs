@@ -1,43 +1,363 @@
+//! A [`Vertex`](trait.Vertex.html) trait for coordinate-bearing vertices, the concrete
+//! [`CoordVertex<F>`](struct.CoordVertex.html) implementor, and the geometric methods
+//! (`map_vertices`/`translate`/`scale`/`transform`/etc.) they enable on `Polytope<V>`.
+//!
+//! The trait is what lets a hand-rolled vertex type — one a caller already built a `Polytope<V>`
+//! out of by hand, with its own `promote`-style closures for `extrude()`/`cone()` — pick up this
+//! whole API by implementing two small methods, rather than being rewritten into `CoordVertex`.
+
+use std::cell::Cell;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+
+use transform::AffineTransform;
 use vector::Vector;
+use {facet_hyperplane, find_initial_simplex, EPSILON, Polytope};
+
+/// A vertex with `f64` coordinates, implemented by any type that wants to use the
+/// affine-transform API (`map_vertices`/`translate`/`scale`/`transform`/etc.) on `Polytope<V>`,
+/// without being rewritten into the crate's own [`CoordVertex`](struct.CoordVertex.html).
+pub trait Vertex: Sized {
+    /// Lends the coordinate slice.
+    fn coords(&self) -> &[f64];
+
+    /// Builds a vertex from a coordinate slice.
+    fn from_coords(coords: &[f64]) -> Self;
+
+    /// Returns the number of coordinates of this vertex.
+    fn dimension(&self) -> usize {
+        self.coords().len()
+    }
+
+    /// Returns a copy of this vertex with `extra` coordinates appended, for building the
+    /// `pull_in`/`push_out` closures of [`extrude()`](../struct.Polytope.html#method.extrude) and
+    /// [`cone()`](../struct.Polytope.html#method.cone).
+    fn concat(&self, extra: &[f64]) -> Self {
+        let mut coords = self.coords().to_vec();
+        coords.extend_from_slice(extra);
+        Self::from_coords(&coords)
+    }
+
+    /// Returns the component-wise sum of this vertex's coordinates and `other`'s.
+    fn add(&self, other: &Self) -> Self {
+        let sum: Vec<f64> = self.coords().iter().zip(other.coords()).map(|(a, b)| a + b).collect();
+        Self::from_coords(&sum)
+    }
+
+    /// Returns this vertex with every coordinate scaled by `factor`.
+    fn scale(&self, factor: f64) -> Self {
+        let scaled: Vec<f64> = self.coords().iter().map(|c| c * factor).collect();
+        Self::from_coords(&scaled)
+    }
+}
 
-pub struct Vertex<F: Clone> {
+/// A concrete, coordinate-bearing vertex, used to build geometric (rather than purely
+/// combinatorial) polytopes.
+pub struct CoordVertex<F: Clone> {
     coords: Vector<F>,
 }
 
-impl<F: Clone> Vertex<F> {
-    pub fn new_trivial() -> Vertex<F> {
-        Vertex::<F> {
+impl<F: Clone> CoordVertex<F> {
+    /// Builds a vertex in 0 dimensions, with an empty coordinate vector.
+    pub fn new_trivial() -> CoordVertex<F> {
+        CoordVertex::<F> {
             coords: Vec::new(),
         }
     }
 
-    pub fn new(coords: &Vector<F>) -> Vertex<F> {
-        Vertex::<F> {
+    /// Builds a vertex from a coordinate vector.
+    pub fn new(coords: &Vector<F>) -> CoordVertex<F> {
+        CoordVertex::<F> {
             coords: coords.clone(),
         }
     }
 
+    /// Returns the number of coordinates of this vertex.
     pub fn dimension(&self) -> usize {
         self.coords.len()
     }
+
+    /// Lends the coordinate vector.
+    pub fn coords(&self) -> &Vector<F> {
+        &self.coords
+    }
+}
+
+impl Vertex for CoordVertex<f64> {
+    fn coords(&self) -> &[f64] {
+        &self.coords
+    }
+
+    fn from_coords(coords: &[f64]) -> Self {
+        CoordVertex::new(&coords.to_vec())
+    }
+}
+
+/// Error returned by [`Polytope::polar_dual()`](struct.Polytope.html#method.polar_dual) when polar
+/// reciprocation about the origin isn't well-defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolarDualError {
+    /// A facet's supporting hyperplane passes through the origin, so its reciprocal `n / c` is
+    /// undefined. This happens when the polytope isn't centered at the origin.
+    FacetThroughOrigin,
+}
+
+impl fmt::Display for PolarDualError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PolarDualError::FacetThroughOrigin => {
+                write!(f, "a facet's hyperplane passes through the origin")
+            }
+        }
+    }
+}
+
+impl Error for PolarDualError {}
+
+impl<V: Vertex> Polytope<V> {
+    /// Applies `f` to every vertex, leaving the incidence structure unchanged.
+    pub fn map_vertices<F>(&self, f: F) -> Self
+        where F: Fn(&V) -> V
+    {
+        let vertices = self.vertices().iter().map(f).collect();
+        let elements = (0..self.dimension()).map(|d| self.elements(d).to_vec()).collect();
+        Polytope::from_elements(vertices, elements)
+    }
+
+    /// Applies an [`AffineTransform`](../transform/struct.AffineTransform.html) to every vertex,
+    /// leaving the incidence structure unchanged.
+    pub fn transform(&self, transform: &AffineTransform) -> Self {
+        self.map_vertices(|v| V::from_coords(&transform.apply(v.coords())))
+    }
+
+    /// Translates every vertex by `offset`.
+    pub fn translate(&self, offset: &[f64]) -> Self {
+        self.transform(&AffineTransform::translation(offset))
+    }
+
+    /// Scales every vertex uniformly by `factor` about the origin.
+    pub fn scale(&self, factor: f64) -> Self {
+        self.transform(&AffineTransform::uniform_scaling(factor, self.vertices()[0].dimension()))
+    }
+
+    /// [`extrude()`](../struct.Polytope.html#method.extrude) along a new axis, pulling one replica
+    /// in to `-half_height` and pushing the other out to `half_height` on that axis — the common
+    /// case of extruding a geometric polytope, sparing the caller from writing `concat` closures.
+    pub fn extrude_along(&self, half_height: f64) -> Self {
+        self.extrude(|v| v.concat(&[-half_height]), |v| v.concat(&[half_height]))
+    }
+
+    /// [`cone()`](../struct.Polytope.html#method.cone) along a new axis to `apex`, pulling one
+    /// replica in to `-half_height` and pushing the other out to `half_height` on that axis.
+    pub fn cone_along(&self, half_height: f64, apex: V) -> Self {
+        self.cone(apex, |v| v.concat(&[-half_height]), |v| v.concat(&[half_height]))
+    }
+
+    /// Returns the [polar dual](https://en.wikipedia.org/wiki/Dual_polyhedron#Polar_reciprocation)
+    /// of the polytope: reverses the face lattice exactly as
+    /// [`dual()`](../struct.Polytope.html#method.dual), but additionally reciprocates vertex
+    /// coordinates about the unit sphere, assuming `self` is centered at the origin.
+    ///
+    /// Each new vertex is `n / c`, where `n . x = c` is the outward-facing supporting hyperplane
+    /// of the corresponding old facet. Returns
+    /// [`PolarDualError::FacetThroughOrigin`](enum.PolarDualError.html) if a facet's hyperplane
+    /// passes through the origin (i.e. `self` is not centered).
+    pub fn polar_dual(&self) -> Result<Self, PolarDualError> {
+        let dimension = self.vertices()[0].dimension();
+        let origin = vec![0.0; dimension];
+
+        let facet_rank = self.dimension() - 1;
+        let facet_count = self.rank_size(facet_rank);
+        let mut reciprocals = Vec::<Vector<f64>>::with_capacity(facet_count);
+        for i in 0..facet_count {
+            let mut vertex_indices = BTreeSet::new();
+            self.collect_vertices(facet_rank, i, &mut vertex_indices);
+            let facet_coords: Vec<Vector<f64>> = vertex_indices.into_iter()
+                .map(|v| self.vertices()[v].coords().to_vec())
+                .collect();
+
+            let basis = find_initial_simplex(&facet_coords, dimension - 1)
+                .expect("a facet should span a (dimension - 1)-dimensional hyperplane");
+            let basis_coords: Vec<Vector<f64>> = basis.iter().map(|&b| facet_coords[b].clone()).collect();
+            let (normal, offset) = facet_hyperplane(&basis_coords, &origin);
+
+            if offset.abs() < EPSILON {
+                return Err(PolarDualError::FacetThroughOrigin);
+            }
+            reciprocals.push(normal.iter().map(|x| x / offset).collect());
+        }
+
+        let next = Cell::new(0);
+        Ok(self.dual(|_| {
+            let i = next.get();
+            next.set(i + 1);
+            V::from_coords(&reciprocals[i])
+        }))
+    }
+
+    /// Returns the axis-aligned `(min, max)` coordinate bounds of the vertex set.
+    ///
+    /// Panics if the polytope has no vertices.
+    pub fn bounds(&self) -> (Vector<f64>, Vector<f64>) {
+        let mut vertices = self.vertices().iter();
+        let first = vertices.next().expect("polytope has no vertices").coords().to_vec();
+        let mut min = first.clone();
+        let mut max = first;
+
+        for v in vertices {
+            for (i, &c) in v.coords().iter().enumerate() {
+                if c < min[i] {
+                    min[i] = c;
+                }
+                if c > max[i] {
+                    max[i] = c;
+                }
+            }
+        }
+
+        (min, max)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use vertex::*;
+    use transform::AffineTransform;
+    use Polytope;
 
     #[test]
     fn new_trivial_vertex() {
-        let v = Vertex::<f32>::new_trivial();
+        let v = CoordVertex::<f32>::new_trivial();
         assert!(v.dimension() == 0);
     }
 
     #[test]
     fn new_vertex() {
-        let v = Vertex::<f32>::new(&[1.0, 2.0, 3.0].to_vec());
+        let v = CoordVertex::<f32>::new(&[1.0, 2.0, 3.0].to_vec());
         assert!(v.dimension() == 3);
         assert!(v.coords[0] == 1.0);
         assert!(v.coords[1] == 2.0);
         assert!(v.coords[2] == 3.0);
     }
+
+    fn square() -> Polytope<CoordVertex<f64>> {
+        let point = Polytope::new(CoordVertex::new(&vec![]));
+        let line = point.extrude(|_| CoordVertex::new(&vec![-1.0]), |_| CoordVertex::new(&vec![1.0]));
+        line.extrude(|v| CoordVertex::new(&[v.coords()[0], -1.0].to_vec()),
+                      |v| CoordVertex::new(&[v.coords()[0], 1.0].to_vec()))
+    }
+
+    #[test]
+    fn transform_translates_every_vertex() {
+        let square = square();
+        let moved = square.transform(&AffineTransform::translation(&[2.0, 0.0]));
+        let (min, max) = moved.bounds();
+        assert_eq!(min, vec![1.0, -1.0]);
+        assert_eq!(max, vec![3.0, 1.0]);
+    }
+
+    #[test]
+    fn bounds_of_square_are_its_corners() {
+        let (min, max) = square().bounds();
+        assert_eq!(min, vec![-1.0, -1.0]);
+        assert_eq!(max, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn extrude_along_builds_a_cube_centered_on_the_origin() {
+        let point = Polytope::new(CoordVertex::new(&vec![]));
+        let cube = point.extrude_along(1.0).extrude_along(1.0).extrude_along(1.0);
+        assert_eq!(cube.dimension(), 3);
+        assert_eq!(cube.vertices().len(), 8);
+        let (min, max) = cube.bounds();
+        assert_eq!(min, vec![-1.0, -1.0, -1.0]);
+        assert_eq!(max, vec![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn cone_along_links_replicas_to_the_given_apex() {
+        let rectangle = square();
+        let bipyramid = rectangle.cone_along(1.0, CoordVertex::new(&vec![0.0, 0.0, 2.0]));
+        assert_eq!(bipyramid.dimension(), 3);
+        assert_eq!(bipyramid.vertices().len(), 9);
+        assert_eq!(bipyramid.elements(2).len(), 2);
+    }
+
+    #[test]
+    fn translate_and_scale_move_and_resize_every_vertex() {
+        let moved = square().translate(&[1.0, 1.0]);
+        assert_eq!(moved.bounds(), (vec![0.0, 0.0], vec![2.0, 2.0]));
+
+        let scaled = square().scale(2.0);
+        assert_eq!(scaled.bounds(), (vec![-2.0, -2.0], vec![2.0, 2.0]));
+    }
+
+    fn cube() -> Polytope<CoordVertex<f64>> {
+        let point = Polytope::new(CoordVertex::new(&vec![]));
+        point.extrude_along(1.0).extrude_along(1.0).extrude_along(1.0)
+    }
+
+    #[test]
+    fn polar_dual_of_cube_is_an_octahedron_at_unit_distance() {
+        let dual = cube().polar_dual().unwrap();
+        assert_eq!(dual.vertices().len(), 6);
+        assert_eq!(dual.elements(0).len(), 12);
+        assert_eq!(dual.elements(1).len(), 8);
+        for v in dual.vertices() {
+            let norm: f64 = v.coords().iter().map(|c| c * c).sum::<f64>().sqrt();
+            assert!((norm - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn polar_dual_rejects_a_facet_through_the_origin() {
+        // Shifting by 1 moves the x = -1 facet onto the origin's hyperplane, x = 0.
+        let shifted = cube().translate(&[1.0, 0.0, 0.0]);
+        match shifted.polar_dual() {
+            Err(PolarDualError::FacetThroughOrigin) => {}
+            other => panic!("expected FacetThroughOrigin, got {:?}", other.is_ok()),
+        }
+    }
+
+    /// A hand-rolled vertex type, standing in for a caller's own geometric point struct that
+    /// predates this module — only `coords`/`from_coords` are implemented, which is enough to
+    /// pick up `map_vertices`/`translate`/`scale`/`transform` without being rewritten
+    /// into `CoordVertex`.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Point {
+        coords: Vec<f64>,
+    }
+
+    impl Vertex for Point {
+        fn coords(&self) -> &[f64] {
+            &self.coords
+        }
+
+        fn from_coords(coords: &[f64]) -> Self {
+            Point { coords: coords.to_vec() }
+        }
+    }
+
+    #[test]
+    fn a_hand_rolled_vertex_type_gains_the_affine_transform_api() {
+        let point = Polytope::new(Point { coords: vec![] });
+        let line = point.extrude_along(1.0);
+        let square = line.extrude_along(1.0);
+
+        let moved = square.translate(&[1.0, 1.0]);
+        assert_eq!(moved.bounds(), (vec![0.0, 0.0], vec![2.0, 2.0]));
+
+        let scaled = square.scale(2.0);
+        assert_eq!(scaled.bounds(), (vec![-2.0, -2.0], vec![2.0, 2.0]));
+    }
+
+    #[test]
+    fn vertex_trait_add_and_scale_are_component_wise() {
+        let a = Point { coords: vec![1.0, 2.0] };
+        let b = Point { coords: vec![3.0, -1.0] };
+        assert_eq!(a.add(&b).coords, vec![4.0, 1.0]);
+        assert_eq!(a.scale(2.0).coords, vec![2.0, 4.0]);
+        assert_eq!(a.concat(&[5.0]).coords, vec![1.0, 2.0, 5.0]);
+    }
 }